@@ -0,0 +1,29 @@
+//! Benchmarks the cached power-of-10 lookup against the naive `powi` call
+//! it replaces in `add`, `sub`, and `to_string_with_precision`.
+
+use big_number::{pow10, BigNumber};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_add(c: &mut Criterion) {
+    let a = BigNumber::new(1.234, 120, 2);
+    let b = BigNumber::new(5.678, 90, 2);
+
+    c.bench_function("add (cached pow10)", |bencher| {
+        bencher.iter(|| black_box(a).add(black_box(b)))
+    });
+}
+
+fn bench_pow10_cached(c: &mut Criterion) {
+    c.bench_function("pow10 (cached)", |bencher| {
+        bencher.iter(|| black_box(pow10(black_box(150))))
+    });
+}
+
+fn bench_powi_baseline(c: &mut Criterion) {
+    c.bench_function("10f64.powi (uncached)", |bencher| {
+        bencher.iter(|| black_box(10f64.powi(black_box(150))))
+    });
+}
+
+criterion_group!(benches, bench_add, bench_pow10_cached, bench_powi_baseline);
+criterion_main!(benches);
@@ -1,8 +1,59 @@
-/// A simple BigNumber implementation using scientific notation
-/// for incremental/idle games, with suffixes for thousands and millions,
-/// configurable precision and trimming of trailing zeros.
+//! A simple BigNumber implementation using scientific notation
+//! for incremental/idle games, with suffixes for thousands and millions,
+//! configurable precision and trimming of trailing zeros.
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::OnceLock;
+
+/// Smallest exponent covered by `CACHED_POWERS`, at the bottom edge of
+/// `f64`'s subnormal range (`1e-324` itself underflows to `0.0`).
+const NUMBER_EXP_MIN: i32 = -324;
+/// Largest exponent covered by `CACHED_POWERS`, matching the highest
+/// finite `f64` power of ten (`1e308`).
+const NUMBER_EXP_MAX: i32 = 308;
+
+/// Lazily-built lookup table of `10f64.powi(exp)` for every `exp` in
+/// `NUMBER_EXP_MIN..=NUMBER_EXP_MAX`, indexed by `exp - NUMBER_EXP_MIN`.
+/// `add`, `sub`, and `to_string_with_precision` sit in the hot path of an
+/// idle-game tick, so this trades a one-time allocation for avoiding
+/// repeated `powi` calls.
+fn cached_powers() -> &'static [f64] {
+    static CACHED_POWERS: OnceLock<Vec<f64>> = OnceLock::new();
+    CACHED_POWERS.get_or_init(|| {
+        (NUMBER_EXP_MIN..=NUMBER_EXP_MAX).map(|exp| 10f64.powi(exp)).collect()
+    })
+}
+
+/// Returns `10f64.powi(exp)`, served from `CACHED_POWERS` when `exp` is in
+/// range and falling back to `powi` otherwise.
+///
+/// Public only so `benches/pow10_benchmark.rs` can compare it directly
+/// against `powi`; not otherwise part of the crate's intended API.
+#[doc(hidden)]
+pub fn pow10(exp: i32) -> f64 {
+    if (NUMBER_EXP_MIN..=NUMBER_EXP_MAX).contains(&exp) {
+        cached_powers()[(exp - NUMBER_EXP_MIN) as usize]
+    } else {
+        10f64.powi(exp)
+    }
+}
+
+/// Controls how [`BigNumber::to_string_with_notation`] renders large values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotationMode {
+    /// `1.23e100`, falling back to K/M/B for smaller magnitudes. Matches
+    /// `to_string`/`to_string_with_precision`.
+    Scientific,
+    /// Named tiers (K, M, B, T, Qa, …) extended indefinitely with
+    /// letter suffixes (aa, ab, … zz, aaa, …) instead of `e`-notation.
+    Standard,
+    /// Like `Standard`, but keeps the exponent explicit (`1.23e15`) with
+    /// the exponent forced to a multiple of 3 instead of a named suffix.
+    Engineering,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct BigNumber {
     pub mantissa: f64,
     pub exponent: i32,
@@ -14,30 +65,69 @@ pub struct BigNumber {
 
 impl BigNumber {
     pub fn new(mantissa: f64, exponent: i32, decimals: u8) -> Self {
+        if mantissa.is_nan() {
+            return BigNumber { mantissa: f64::NAN, exponent: 0, decimals };
+        }
+        if mantissa.is_infinite() {
+            return BigNumber { mantissa, exponent: 0, decimals };
+        }
+
         let mut m = mantissa;
         let mut e = exponent;
-        while m >= 10.0 && e < i32::MAX {
+        while m.abs() >= 10.0 && e < i32::MAX {
             m /= 10.0;
             e += 1;
         }
-        while m < 1.0 && m != 0.0 && e > i32::MIN {
+        while m.abs() < 1.0 && m != 0.0 && e > i32::MIN {
             m *= 10.0;
             e -= 1;
         }
         BigNumber { mantissa: m, exponent: e, decimals }
     }
     pub fn zero() -> Self {
-        let mut m = 0.0;
-        let mut e = 1;
+        let m = 0.0;
+        let e = 1;
         BigNumber { mantissa: m, exponent: e, decimals: 2 }
     }
     pub fn one() -> Self {
-        let mut m = 1.0;
-        let mut e = 0;
+        let m = 1.0;
+        let e = 0;
         BigNumber { mantissa: m, exponent: e, decimals: 2 }
     }
+    pub fn nan() -> Self {
+        BigNumber { mantissa: f64::NAN, exponent: 0, decimals: 2 }
+    }
+    pub fn infinity() -> Self {
+        BigNumber { mantissa: f64::INFINITY, exponent: 0, decimals: 2 }
+    }
+    pub fn neg_infinity() -> Self {
+        BigNumber { mantissa: f64::NEG_INFINITY, exponent: 0, decimals: 2 }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.mantissa.is_nan()
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.mantissa.is_infinite()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.mantissa.is_finite()
+    }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: BigNumber) -> BigNumber {
+        if self.is_nan() || other.is_nan() {
+            return BigNumber::nan();
+        }
+        if self.is_infinite() || other.is_infinite() {
+            if self.is_infinite() && other.is_infinite() && self.mantissa.signum() != other.mantissa.signum() {
+                return BigNumber::nan();
+            }
+            return if self.is_infinite() { self } else { other };
+        }
+
         if self.exponent == other.exponent {
             return BigNumber::new(self.mantissa + other.mantissa, self.exponent, self.decimals);
         }
@@ -54,37 +144,39 @@ impl BigNumber {
             return high;
         }
 
-        let scaled_low = low.mantissa / 10f64.powi(diff);
+        let scaled_low = low.mantissa / pow10(diff);
         let result_mantissa = high.mantissa + scaled_low;
 
         BigNumber::new(result_mantissa, high.exponent, self.decimals)
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(self, other: BigNumber) -> BigNumber {
-        if self.exponent == other.exponent {
-            BigNumber::new(self.mantissa - other.mantissa, self.exponent, self.decimals)
-        } else if self.exponent > other.exponent {
-            BigNumber::new(
-                self.mantissa - other.mantissa / 10f64.powi(self.exponent - other.exponent),
-                self.exponent,
-                self.decimals
-            )
-        } else {
-            BigNumber::new(
-                self.mantissa / 10f64.powi(other.exponent - self.exponent) - other.mantissa,
-                other.exponent,
-                self.decimals
-            )
-        }
+        self.add(other.neg())
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn mul(self, other: BigNumber) -> BigNumber {
+        if self.is_nan() || other.is_nan() {
+            return BigNumber::nan();
+        }
+        if self.is_infinite() || other.is_infinite() {
+            if self.mantissa == 0.0 || other.mantissa == 0.0 {
+                return BigNumber::nan();
+            }
+            let sign = self.mantissa.signum() * other.mantissa.signum();
+            return BigNumber::new(sign * f64::INFINITY, 0, self.decimals);
+        }
+
         BigNumber::new(self.mantissa * other.mantissa, self.exponent + other.exponent, self.decimals)
     }
 
+    /// Division by zero yields `Infinity`/`-Infinity` (or `NaN` for `0 / 0`)
+    /// rather than panicking, matching plain `f64` semantics.
+    #[allow(clippy::should_implement_trait)]
     pub fn div(self, other: BigNumber) -> BigNumber {
-        if other.mantissa == 0.0 {
-            panic!("Division by zero");
+        if self.is_nan() || other.is_nan() {
+            return BigNumber::nan();
         }
 
         let new_mantissa = self.mantissa / other.mantissa;
@@ -93,27 +185,146 @@ impl BigNumber {
         BigNumber::new(new_mantissa, new_exponent, self.decimals)
     }
 
+    /// Base-10 logarithm, computed without ever materializing `mantissa * 10^exponent`.
+    pub fn log10(self) -> f64 {
+        self.exponent as f64 + self.mantissa.log10()
+    }
+
+    /// Logarithm in an arbitrary base, via `log10(self) / log10(base)`.
+    pub fn log(self, base: f64) -> f64 {
+        self.log10() / base.log10()
+    }
+
+    /// Natural logarithm, via `log10(self) * ln(10)`.
+    pub fn ln(self) -> f64 {
+        self.log10() * std::f64::consts::LN_10
+    }
+
+    /// `e^x` as a [`BigNumber`], via `10^(x * log10(e))`. The counterpart to [`BigNumber::ln`].
+    pub fn exp(x: f64) -> BigNumber {
+        if x.is_nan() {
+            return BigNumber::nan();
+        }
+        let l = x * std::f64::consts::LOG10_E;
+        let new_exp = l.floor() as i32;
+        let new_mantissa = 10f64.powf(l - l.floor());
+        BigNumber::new(new_mantissa, new_exp, 2)
+    }
+
+    /// Raises `self` to the power `n`. Zero and negative bases (`NaN` unless
+    /// `n` is an integer), and `NaN`/`Infinity` inputs, are handled directly
+    /// rather than through `log10` so the function stays total.
+    pub fn pow(self, n: f64) -> BigNumber {
+        if self.is_nan() || n.is_nan() {
+            return BigNumber::nan();
+        }
+        if n == 0.0 {
+            return BigNumber::one();
+        }
+        if self.mantissa == 0.0 {
+            return if n > 0.0 { BigNumber::zero() } else { BigNumber::infinity() };
+        }
+        if n.is_infinite() {
+            return match (self.cmp_abs(&BigNumber::one()), n > 0.0) {
+                (Ordering::Equal, _) => BigNumber::one(),
+                (Ordering::Greater, true) | (Ordering::Less, false) => BigNumber::infinity(),
+                _ => BigNumber::zero(),
+            };
+        }
+        if self.mantissa < 0.0 {
+            if n.fract() != 0.0 {
+                return BigNumber::nan();
+            }
+            let magnitude = BigNumber::new(self.mantissa.abs(), self.exponent, self.decimals).pow(n);
+            return if (n as i64).rem_euclid(2) != 0 { magnitude.neg() } else { magnitude };
+        }
+        if self.is_infinite() {
+            return if n > 0.0 { BigNumber::infinity() } else { BigNumber::zero() };
+        }
+
+        let l = self.log10() * n;
+        let new_exp = l.floor() as i32;
+        let new_mantissa = 10f64.powf(l - l.floor());
+        BigNumber::new(new_mantissa, new_exp, self.decimals)
+    }
+
+    /// Square root, via `pow(0.5)`.
+    pub fn sqrt(self) -> BigNumber {
+        self.pow(0.5)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> BigNumber {
+        BigNumber::new(-self.mantissa, self.exponent, self.decimals)
+    }
+
+    /// Compares magnitude only, ignoring sign. A zero mantissa always compares
+    /// below any non-zero magnitude, regardless of the exponent it carries.
+    pub fn cmp_abs(&self, other: &BigNumber) -> Ordering {
+        if self.is_nan() || other.is_nan() {
+            return match (self.is_nan(), other.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                _ => unreachable!(),
+            };
+        }
+
+        let (m1, m2) = (self.mantissa.abs(), other.mantissa.abs());
+        if self.is_infinite() || other.is_infinite() {
+            return m1.partial_cmp(&m2).unwrap();
+        }
+        if m1 == 0.0 && m2 == 0.0 {
+            return Ordering::Equal;
+        }
+        if m1 == 0.0 {
+            return Ordering::Less;
+        }
+        if m2 == 0.0 {
+            return Ordering::Greater;
+        }
+        self.exponent.cmp(&other.exponent).then_with(|| m1.partial_cmp(&m2).unwrap())
+    }
+
+    pub fn min(self, other: BigNumber) -> BigNumber {
+        if self <= other { self } else { other }
+    }
+
+    pub fn max(self, other: BigNumber) -> BigNumber {
+        if self >= other { self } else { other }
+    }
+
+    fn sign(&self) -> i32 {
+        if self.mantissa > 0.0 {
+            1
+        } else if self.mantissa < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         self.to_string_with_precision(self.decimals as usize)
     }
 
     pub fn to_string_with_precision(&self, precision: usize) -> String {
+        if self.mantissa.is_nan() {
+            return "NaN".to_string();
+        }
+        if self.mantissa.is_infinite() {
+            return if self.mantissa > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+        }
         if self.mantissa == 0.0 {
             return "0".to_string();
         }
 
         if self.exponent > 12 || self.mantissa.abs() >= 10.0 {
-            let mut mantissa_str = format!("{:.*}", precision, self.mantissa.abs());
-            if mantissa_str.contains('.') {
-                mantissa_str = mantissa_str.trim_end_matches('0').trim_end_matches('.').to_string();
-            }
-            if self.mantissa < 0.0 {
-                mantissa_str = format!("-{}", mantissa_str);
-            }
-            return format!("{}e{}", mantissa_str, self.exponent);
+            return signed_scientific(self.mantissa, self.exponent, precision);
         }
 
-        let val = self.mantissa * 10f64.powi(self.exponent);
+        let val = self.mantissa * pow10(self.exponent);
 
         let (scaled_val, suffix) = if val.abs() < 1e3 {
             (val, "")
@@ -124,22 +335,433 @@ impl BigNumber {
         } else if val.abs() < 1e12 {
             (val / 1e9, "B")
         } else {
-            let mut mantissa_str = format!("{:.*}", precision, self.mantissa.abs());
-            if mantissa_str.contains('.') {
-                mantissa_str = mantissa_str.trim_end_matches('0').trim_end_matches('.').to_string();
+            return signed_scientific(self.mantissa, self.exponent, precision);
+        };
+
+        format_trimmed(scaled_val, precision) + suffix
+    }
+
+    /// Renders the number using the given [`NotationMode`]. `Scientific`
+    /// matches `to_string_with_precision`; `Standard` extends the suffix
+    /// ladder past "B" instead of falling back to `e`-notation, and
+    /// `Engineering` writes an `e` exponent that is a multiple of 3.
+    pub fn to_string_with_notation(&self, mode: NotationMode, precision: usize) -> String {
+        if self.mantissa.is_nan() {
+            return "NaN".to_string();
+        }
+        if self.mantissa.is_infinite() {
+            return if self.mantissa > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+        }
+        if self.mantissa == 0.0 {
+            return "0".to_string();
+        }
+
+        match mode {
+            NotationMode::Scientific => self.to_string_with_precision(precision),
+            NotationMode::Standard => self.to_string_grouped(precision, true),
+            NotationMode::Engineering => self.to_string_grouped(precision, false),
+        }
+    }
+
+    fn to_string_grouped(self, precision: usize, named: bool) -> String {
+        let tier = self.exponent.div_euclid(3);
+
+        if tier <= 0 {
+            return format_trimmed(self.mantissa * pow10(self.exponent), precision);
+        }
+
+        let remainder = self.exponent.rem_euclid(3);
+        let scaled = self.mantissa.abs() * pow10(remainder);
+        let mut s = format_trimmed(scaled, precision);
+
+        if named {
+            s.push_str(&tier_suffix(tier as u64));
+        } else {
+            s.push('e');
+            s.push_str(&(tier * 3).to_string());
+        }
+
+        if self.mantissa < 0.0 {
+            format!("-{}", s)
+        } else {
+            s
+        }
+    }
+}
+
+/// Named tiers for `NotationMode::Standard`, indexed by `exponent / 3`.
+/// Past "Dc" (tier 11), tiers are generated as letter suffixes
+/// (`aa`, `ab`, … `az`, `ba`, … `zz`, `aaa`, …) the way incremental games do.
+const STANDARD_TIERS: [&str; 12] = ["", "K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No", "Dc"];
+
+fn tier_suffix(tier: u64) -> String {
+    match STANDARD_TIERS.get(tier as usize) {
+        Some(name) => name.to_string(),
+        None => letter_suffix(tier as usize - STANDARD_TIERS.len()),
+    }
+}
+
+/// Bijective base-26 suffix, offset so the one-letter block ("a".."z") is
+/// skipped and the sequence starts directly at the two-letter block: `0` ->
+/// "aa", `25` -> "az", `26` -> "ba", … `675` -> "zz", `676` -> "aaa", …
+/// Never runs out, so there's always a next tier. `decode_letter_suffix` is
+/// the inverse of this function.
+fn letter_suffix(idx: usize) -> String {
+    let mut n = idx as u64 + 26;
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'a' + (n % 26) as u8) as char);
+        if n < 26 {
+            break;
+        }
+        n = n / 26 - 1;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Formats `value` to `precision` decimals, trimming trailing zeros (and a
+/// trailing decimal point) the way every suffix/`e`-notation branch does.
+fn format_trimmed(value: f64, precision: usize) -> String {
+    let mut s = format!("{:.*}", precision, value);
+    if s.contains('.') {
+        s = s.trim_end_matches('0').trim_end_matches('.').to_string();
+    }
+    s
+}
+
+/// Formats `mantissa * 10^exponent` in scientific notation, e.g. `"1.23e100"`.
+fn signed_scientific(mantissa: f64, exponent: i32, precision: usize) -> String {
+    let mut s = format_trimmed(mantissa.abs(), precision);
+    if mantissa < 0.0 {
+        s = format!("-{}", s);
+    }
+    format!("{}e{}", s, exponent)
+}
+
+/// Agrees with `Ord`/`cmp`: `decimals` (a display setting, not a value) is
+/// ignored, and two `NaN`s compare equal, so `Eq`'s reflexivity holds and
+/// sorted collections (`BTreeSet`, etc.) behave consistently.
+impl PartialEq for BigNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigNumber {}
+
+impl Ord for BigNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.is_nan() || other.is_nan() {
+            return match (self.is_nan(), other.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                _ => unreachable!(),
+            };
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return self.mantissa.partial_cmp(&other.mantissa).unwrap();
+        }
+
+        let (s1, s2) = (self.sign(), other.sign());
+        if s1 != s2 {
+            return s1.cmp(&s2);
+        }
+        if s1 == 0 {
+            return Ordering::Equal;
+        }
+        let exp_order = if s1 > 0 {
+            self.exponent.cmp(&other.exponent)
+        } else {
+            other.exponent.cmp(&self.exponent)
+        };
+        exp_order.then_with(|| {
+            if s1 > 0 {
+                self.mantissa.partial_cmp(&other.mantissa).unwrap()
+            } else {
+                other.mantissa.partial_cmp(&self.mantissa).unwrap()
             }
-            if self.mantissa < 0.0 {
-                mantissa_str = format!("-{}", mantissa_str);
+        })
+    }
+}
+
+impl PartialOrd for BigNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigNumber {
+    type Output = BigNumber;
+    fn add(self, other: BigNumber) -> BigNumber {
+        BigNumber::add(self, other)
+    }
+}
+
+impl Sub for BigNumber {
+    type Output = BigNumber;
+    fn sub(self, other: BigNumber) -> BigNumber {
+        BigNumber::sub(self, other)
+    }
+}
+
+impl Mul for BigNumber {
+    type Output = BigNumber;
+    fn mul(self, other: BigNumber) -> BigNumber {
+        BigNumber::mul(self, other)
+    }
+}
+
+impl Div for BigNumber {
+    type Output = BigNumber;
+    fn div(self, other: BigNumber) -> BigNumber {
+        BigNumber::div(self, other)
+    }
+}
+
+impl Neg for BigNumber {
+    type Output = BigNumber;
+    fn neg(self) -> BigNumber {
+        BigNumber::neg(self)
+    }
+}
+
+impl AddAssign for BigNumber {
+    fn add_assign(&mut self, other: BigNumber) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for BigNumber {
+    fn sub_assign(&mut self, other: BigNumber) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for BigNumber {
+    fn mul_assign(&mut self, other: BigNumber) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for BigNumber {
+    fn div_assign(&mut self, other: BigNumber) {
+        *self = *self / other;
+    }
+}
+
+/// Why a string could not be parsed into a [`BigNumber`] via [`FromStr`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigNumberError {
+    /// The input was empty (or only a sign) after trimming.
+    Empty,
+    /// No digits were found where a mantissa was expected.
+    InvalidDigit(String),
+    /// The `e`/`E` exponent suffix was not a valid signed integer.
+    InvalidExponent(String),
+    /// The trailing suffix did not match a known tier (e.g. "K", "Qa", "aa").
+    UnknownSuffix(String),
+    /// Characters were left over after the mantissa, exponent, and suffix were consumed.
+    TrailingCharacters(String),
+}
+
+impl std::fmt::Display for ParseBigNumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBigNumberError::Empty => write!(f, "cannot parse BigNumber from an empty string"),
+            ParseBigNumberError::InvalidDigit(s) => write!(f, "no digits found in {:?}", s),
+            ParseBigNumberError::InvalidExponent(s) => write!(f, "invalid exponent in {:?}", s),
+            ParseBigNumberError::UnknownSuffix(s) => write!(f, "unknown suffix {:?}", s),
+            ParseBigNumberError::TrailingCharacters(s) => write!(f, "unexpected trailing characters {:?}", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseBigNumberError {}
+
+/// Reverses [`tier_suffix`]: maps a named tier ("K", "Qa", …) or a
+/// [`letter_suffix`] tier ("aa", "ba", …, "aaa", …) back to its
+/// `exponent / 3` value. Returns `None` for anything else, including the
+/// empty string (which is not itself a suffix).
+fn suffix_tier(suffix: &str) -> Option<i32> {
+    if let Some(pos) = STANDARD_TIERS.iter().position(|&tier| tier == suffix) {
+        return if pos == 0 { None } else { Some(pos as i32) };
+    }
+    let idx = decode_letter_suffix(suffix)?;
+    Some(STANDARD_TIERS.len() as i32 + idx)
+}
+
+/// Inverse of [`letter_suffix`]: decodes a lowercase bijective base-26
+/// suffix back to its index, or `None` if `suffix` isn't one (wrong
+/// characters, or too short to have come from the offset `letter_suffix`
+/// uses — plain "a".."z" are never produced by it).
+fn decode_letter_suffix(suffix: &str) -> Option<i32> {
+    // 26^7 already exceeds i32::MAX, so anything longer can never decode
+    // to a valid tier; bail out before the accumulator could overflow i64.
+    if suffix.is_empty() || suffix.len() > 7 || !suffix.bytes().all(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    let mut n: i64 = -1;
+    for b in suffix.bytes() {
+        n = 26 * (n + 1) + (b - b'a') as i64;
+    }
+    i32::try_from(n - 26).ok().filter(|&idx| idx >= 0)
+}
+
+impl std::str::FromStr for BigNumber {
+    type Err = ParseBigNumberError;
+
+    /// Parses plain decimals ("1234.5"), scientific notation ("1.23e100",
+    /// matching `to_string`'s output), and the suffix forms this crate emits
+    /// ("300K", "1.23B", "1aa", …). Digits are accumulated one at a time
+    /// (`acc = acc * 10 + digit`), tracking how many fell after the decimal
+    /// point, then combined with any explicit `eNNN`/suffix exponent and
+    /// normalized through [`BigNumber::new`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        match trimmed {
+            "NaN" => return Ok(BigNumber::nan()),
+            "Infinity" | "inf" => return Ok(BigNumber::infinity()),
+            "-Infinity" | "-inf" => return Ok(BigNumber::neg_infinity()),
+            "" => return Err(ParseBigNumberError::Empty),
+            _ => {}
+        }
+
+        let bytes = trimmed.as_bytes();
+        let mut idx = 0;
+        let negative = match bytes[0] {
+            b'-' => {
+                idx += 1;
+                true
+            }
+            b'+' => {
+                idx += 1;
+                false
+            }
+            _ => false,
+        };
+        if idx == bytes.len() {
+            return Err(ParseBigNumberError::Empty);
+        }
+
+        let mut mantissa_acc: f64 = 0.0;
+        let mut frac_digits: i32 = 0;
+        let mut seen_decimal = false;
+        let mut seen_digit = false;
+
+        while idx < bytes.len() {
+            match bytes[idx] {
+                digit @ b'0'..=b'9' => {
+                    mantissa_acc = mantissa_acc * 10.0 + (digit - b'0') as f64;
+                    if seen_decimal {
+                        frac_digits += 1;
+                    }
+                    seen_digit = true;
+                    idx += 1;
+                }
+                b'.' if !seen_decimal => {
+                    seen_decimal = true;
+                    idx += 1;
+                }
+                _ => break,
+            }
+        }
+
+        if !seen_digit {
+            return Err(ParseBigNumberError::InvalidDigit(trimmed.to_string()));
+        }
+
+        let mut exponent_adjust: i32 = -frac_digits;
+
+        // Only commit to exponent parsing when `e`/`E` is actually followed by
+        // an optional sign and at least one digit. Otherwise it's the start of
+        // a suffix (e.g. the "ea".."ez" standard tiers), so fall through to
+        // `suffix_tier` below instead of erroring.
+        let is_exponent_marker = idx < bytes.len() && (bytes[idx] == b'e' || bytes[idx] == b'E') && {
+            let mut peek = idx + 1;
+            if peek < bytes.len() && (bytes[peek] == b'+' || bytes[peek] == b'-') {
+                peek += 1;
             }
-            return format!("{}e{}", mantissa_str, self.exponent);
+            peek < bytes.len() && bytes[peek].is_ascii_digit()
         };
 
-        let mut s = format!("{:.*}", precision, scaled_val);
-        if s.contains('.') {
-            s = s.trim_end_matches('0').trim_end_matches('.').to_string();
+        if is_exponent_marker {
+            idx += 1;
+            let exp_start = idx;
+            if idx < bytes.len() && (bytes[idx] == b'+' || bytes[idx] == b'-') {
+                idx += 1;
+            }
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+            let explicit_exp: i32 = trimmed[exp_start..idx]
+                .parse()
+                .map_err(|_| ParseBigNumberError::InvalidExponent(trimmed.to_string()))?;
+            exponent_adjust += explicit_exp;
+        } else if idx < bytes.len() {
+            let suffix = &trimmed[idx..];
+            let tier = suffix_tier(suffix).ok_or_else(|| ParseBigNumberError::UnknownSuffix(suffix.to_string()))?;
+            exponent_adjust += tier * 3;
+            idx = trimmed.len();
+        }
+
+        if idx != trimmed.len() {
+            return Err(ParseBigNumberError::TrailingCharacters(trimmed[idx..].to_string()));
         }
 
-        s + suffix
+        let signed_mantissa = if negative { -mantissa_acc } else { mantissa_acc };
+        Ok(BigNumber::new(signed_mantissa, exponent_adjust, 2))
+    }
+}
+
+impl BigNumber {
+    /// Inherent convenience wrapper around the [`FromStr`] impl.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<BigNumber, ParseBigNumberError> {
+        s.parse()
+    }
+}
+
+/// Optional `serde` support, enabled via the `serde` feature.
+///
+/// `BigNumber` serializes to its canonical string form (`to_string`) so
+/// save files stay human-readable and survive field reordering. It
+/// deserializes from either that string or the `{mantissa, exponent,
+/// decimals}` struct form, for numeric-only producers.
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::BigNumber;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BigNumberRepr {
+        Str(String),
+        Struct { mantissa: f64, exponent: i32, decimals: u8 },
+    }
+
+    impl Serialize for BigNumber {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for BigNumber {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            match BigNumberRepr::deserialize(deserializer)? {
+                BigNumberRepr::Str(s) => s.parse().map_err(D::Error::custom),
+                BigNumberRepr::Struct { mantissa, exponent, decimals } => {
+                    Ok(BigNumber::new(mantissa, exponent, decimals))
+                }
+            }
+        }
     }
 }
 
@@ -237,4 +859,346 @@ mod tests {
         let s = big.to_string_with_precision(2);
         assert_eq!(s, "17976931348623157580412819756850388593900235011794141176754562789180111453639664485361928830517704263393537268510363518759043843737070229269956251768752166883397940628862983287625967246810352023792017211936260189893797509826303293149283469713429932049693599732425511693654044437030940398714664210204414967808e2147483647");
     }
+
+    #[test]
+    fn test_operator_traits() {
+        let a = BigNumber::new(1.0, 10, 2);
+        let b = BigNumber::new(2.0, 10, 2);
+        assert_eq!((a + b).to_string(), "30B");
+        assert_eq!((b - a).to_string(), "10B");
+        assert_eq!((BigNumber::new(2.0, 5, 2) * BigNumber::new(3.0, 6, 2)).to_string(), "600B");
+        assert_eq!((BigNumber::new(6.0, 10, 2) / BigNumber::new(2.0, 5, 2)).to_string(), "300K");
+        assert_eq!((-a).to_string(), "-10B");
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut a = BigNumber::new(1.0, 10, 2);
+        a += BigNumber::new(1.0, 10, 2);
+        assert_eq!(a.to_string(), "20B");
+        a -= BigNumber::new(1.0, 10, 2);
+        assert_eq!(a.to_string(), "10B");
+        a *= BigNumber::new(2.0, 0, 2);
+        assert_eq!(a.to_string(), "20B");
+        a /= BigNumber::new(2.0, 0, 2);
+        assert_eq!(a.to_string(), "10B");
+    }
+
+    #[test]
+    fn test_total_ordering() {
+        let neg = BigNumber::new(-1.0, 5, 2);
+        let zero = BigNumber::zero();
+        let small = BigNumber::new(1.0, 2, 2);
+        let big = BigNumber::new(1.0, 10, 2);
+        assert!(neg < zero);
+        assert!(zero < small);
+        assert!(small < big);
+        assert_eq!(BigNumber::new(2.0, 3, 2).max(BigNumber::new(5.0, 3, 2)), BigNumber::new(5.0, 3, 2));
+        assert_eq!(BigNumber::new(2.0, 3, 2).min(BigNumber::new(5.0, 3, 2)), BigNumber::new(2.0, 3, 2));
+    }
+
+    #[test]
+    fn test_eq_matches_ord_and_ignores_decimals() {
+        use std::collections::BTreeSet;
+
+        let a = BigNumber::new(3.0, 10, 2);
+        let b = BigNumber::new(3.0, 10, 5);
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        let mut set = BTreeSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+
+        let nan = BigNumber::nan();
+        assert_eq!(nan, nan);
+    }
+
+    #[test]
+    fn test_cmp_abs() {
+        let a = BigNumber::new(-5.0, 10, 2);
+        let b = BigNumber::new(1.0, 10, 2);
+        assert_eq!(a.cmp_abs(&b), Ordering::Greater);
+        assert_eq!(BigNumber::zero().cmp_abs(&b), Ordering::Less);
+    }
+
+    #[test]
+    fn test_pow10_matches_powi() {
+        for exp in [-324, -10, 0, 1, 100, 308] {
+            assert_eq!(pow10(exp), 10f64.powi(exp));
+        }
+        // out-of-range exponents still fall back to `powi` correctly.
+        assert_eq!(pow10(400), 10f64.powi(400));
+    }
+
+    #[test]
+    fn test_div_by_zero_yields_infinity() {
+        let a = BigNumber::new(5.0, 10, 2);
+        let zero = BigNumber::zero();
+        assert_eq!(a.div(zero).to_string(), "Infinity");
+        assert_eq!((-a).div(zero).to_string(), "-Infinity");
+        assert_eq!(zero.div(zero).to_string(), "NaN");
+    }
+
+    #[test]
+    fn test_nan_and_infinity_predicates() {
+        let nan = BigNumber::nan();
+        let inf = BigNumber::infinity();
+        let neg_inf = BigNumber::neg_infinity();
+        let finite = BigNumber::one();
+
+        assert!(nan.is_nan());
+        assert!(!nan.is_finite());
+        assert!(inf.is_infinite());
+        assert!(!inf.is_finite());
+        assert!(neg_inf.is_infinite());
+        assert!(finite.is_finite());
+        assert!(!finite.is_nan() && !finite.is_infinite());
+    }
+
+    #[test]
+    fn test_nan_and_infinity_propagate_through_arithmetic() {
+        let nan = BigNumber::nan();
+        let inf = BigNumber::infinity();
+        let neg_inf = BigNumber::neg_infinity();
+        let finite = BigNumber::new(3.0, 5, 2);
+
+        assert!((nan + finite).is_nan());
+        assert!((finite * nan).is_nan());
+        assert_eq!((inf + finite).to_string(), "Infinity");
+        assert_eq!((finite - inf).to_string(), "-Infinity");
+        assert!((inf + neg_inf).is_nan());
+        assert_eq!((inf * finite).to_string(), "Infinity");
+        assert_eq!((neg_inf * finite).to_string(), "-Infinity");
+    }
+
+    #[test]
+    fn test_nan_and_infinity_to_string() {
+        assert_eq!(BigNumber::nan().to_string(), "NaN");
+        assert_eq!(BigNumber::infinity().to_string(), "Infinity");
+        assert_eq!(BigNumber::neg_infinity().to_string(), "-Infinity");
+    }
+
+    #[test]
+    fn test_nan_sorts_as_total_order_maximum() {
+        let nan = BigNumber::nan();
+        let inf = BigNumber::infinity();
+        assert_eq!(nan.cmp(&nan), Ordering::Equal);
+        assert_eq!(nan.cmp(&inf), Ordering::Greater);
+        assert_eq!(inf.cmp(&BigNumber::new(1.0, 1000, 2)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_standard_notation_named_tiers() {
+        let t = BigNumber::new(1.0, 12, 2);
+        assert_eq!(t.to_string_with_notation(NotationMode::Standard, 2), "1T");
+        let qa = BigNumber::new(1.0, 15, 2);
+        assert_eq!(qa.to_string_with_notation(NotationMode::Standard, 2), "1Qa");
+        let dc = BigNumber::new(1.0, 33, 2);
+        assert_eq!(dc.to_string_with_notation(NotationMode::Standard, 2), "1Dc");
+        let small = BigNumber::new(1.23456, 0, 2);
+        assert_eq!(small.to_string_with_notation(NotationMode::Standard, 2), "1.23");
+    }
+
+    #[test]
+    fn test_standard_notation_letter_tiers() {
+        let aa = BigNumber::new(1.0, 36, 2); // tier 12, first past Dc
+        assert_eq!(aa.to_string_with_notation(NotationMode::Standard, 2), "1aa");
+        let ba = BigNumber::new(1.0, 114, 2); // tier 38 = STANDARD_TIERS.len() + 26
+        assert_eq!(ba.to_string_with_notation(NotationMode::Standard, 2), "1ba");
+    }
+
+    #[test]
+    fn test_parse_round_trips_letter_suffixes() {
+        // "ea".."ez" (tier 116, exponent 348) start with 'e' like an explicit
+        // exponent marker, but aren't one, and must still round-trip.
+        let ea = BigNumber::new(1.0, 348, 2);
+        let rendered = ea.to_string_with_notation(NotationMode::Standard, 2);
+        assert_eq!(rendered, "1ea");
+        let parsed: BigNumber = rendered.parse().unwrap();
+        assert_eq!(parsed.to_string_with_notation(NotationMode::Standard, 2), "1ea");
+
+        // "zz" is the last two-letter tier; "aaa" is the first three-letter
+        // one, reached by continuing past it.
+        let zz = BigNumber::new(1.0, 2061, 2); // tier 687 = STANDARD_TIERS.len() + 675
+        let rendered = zz.to_string_with_notation(NotationMode::Standard, 2);
+        assert_eq!(rendered, "1zz");
+        assert_eq!(rendered.parse::<BigNumber>().unwrap().to_string_with_notation(NotationMode::Standard, 2), "1zz");
+
+        let aaa = BigNumber::new(1.0, 2064, 2); // tier 688 = STANDARD_TIERS.len() + 676
+        let rendered = aaa.to_string_with_notation(NotationMode::Standard, 2);
+        assert_eq!(rendered, "1aaa");
+        assert_eq!(rendered.parse::<BigNumber>().unwrap().to_string_with_notation(NotationMode::Standard, 2), "1aaa");
+    }
+
+    #[test]
+    fn test_standard_notation_negative_mantissa() {
+        let n = BigNumber::new(-2.5, 15, 2);
+        assert_eq!(n.to_string_with_notation(NotationMode::Standard, 2), "-2.5Qa");
+    }
+
+    #[test]
+    fn test_engineering_notation() {
+        let n = BigNumber::new(1.5, 16, 2);
+        assert_eq!(n.to_string_with_notation(NotationMode::Engineering, 2), "15e15");
+    }
+
+    #[test]
+    fn test_scientific_notation_matches_default() {
+        let n = BigNumber::new(1.23, 100, 2);
+        assert_eq!(n.to_string_with_notation(NotationMode::Scientific, 2), n.to_string_with_precision(2));
+    }
+
+    #[test]
+    fn test_parse_plain_decimal() {
+        let n: BigNumber = "1234.5".parse().unwrap();
+        assert_eq!(n.to_string_with_precision(2), "1.23K");
+        let neg: BigNumber = "-42".parse().unwrap();
+        assert_eq!(neg.to_string(), "-42");
+    }
+
+    #[test]
+    fn test_parse_scientific() {
+        let n: BigNumber = "1.23e100".parse().unwrap();
+        assert_eq!(n.to_string_with_precision(2), "1.23e100");
+        let neg: BigNumber = "-1.5E10".parse().unwrap();
+        assert_eq!(neg.to_string_with_precision(2), "-15B");
+    }
+
+    #[test]
+    fn test_parse_suffixed() {
+        let k: BigNumber = "300K".parse().unwrap();
+        assert_eq!(k.to_string(), "300K");
+        let b: BigNumber = "1.23B".parse().unwrap();
+        assert_eq!(b.to_string_with_precision(2), "1.23B");
+        let aa: BigNumber = "1aa".parse().unwrap();
+        assert_eq!(aa.to_string_with_notation(NotationMode::Standard, 2), "1aa");
+    }
+
+    #[test]
+    fn test_parse_round_trips_to_string() {
+        let original = BigNumber::new(1.23, 100, 2);
+        let parsed: BigNumber = original.to_string().parse().unwrap();
+        assert_eq!(parsed.to_string(), original.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<BigNumber>(), Err(ParseBigNumberError::Empty));
+        assert!(matches!("abc".parse::<BigNumber>(), Err(ParseBigNumberError::InvalidDigit(_))));
+        // "e" with nothing digit-shaped after it isn't a valid exponent, so it
+        // falls through to suffix parsing instead (and fails there, since "e"
+        // alone isn't a tier).
+        assert!(matches!("1e".parse::<BigNumber>(), Err(ParseBigNumberError::UnknownSuffix(_))));
+        assert!(matches!("1e+".parse::<BigNumber>(), Err(ParseBigNumberError::UnknownSuffix(_))));
+        assert!(matches!("1e99999999999".parse::<BigNumber>(), Err(ParseBigNumberError::InvalidExponent(_))));
+        assert!(matches!("1Zz".parse::<BigNumber>(), Err(ParseBigNumberError::UnknownSuffix(_))));
+        assert!(matches!("1e5K".parse::<BigNumber>(), Err(ParseBigNumberError::TrailingCharacters(_))));
+    }
+
+    #[test]
+    fn test_from_str_inherent_constructor() {
+        assert_eq!(BigNumber::from_str("300K").unwrap().to_string(), "300K");
+    }
+
+    #[test]
+    fn test_log10() {
+        let n = BigNumber::new(1.0, 100, 2);
+        assert!((n.log10() - 100.0).abs() < 1e-9);
+        assert_eq!(BigNumber::zero().log10(), f64::NEG_INFINITY);
+        assert!(BigNumber::new(-5.0, 3, 2).log10().is_nan());
+        assert!(BigNumber::nan().log10().is_nan());
+        assert_eq!(BigNumber::infinity().log10(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_log_and_ln() {
+        let n = BigNumber::new(8.0, 0, 2);
+        assert!((n.log(2.0) - 3.0).abs() < 1e-9);
+        assert!((n.ln() - 8f64.ln()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_exp() {
+        let n = BigNumber::exp(10.0);
+        assert!((n.ln() - 10.0).abs() < 1e-6);
+        assert!(BigNumber::exp(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pow_basic() {
+        let n = BigNumber::new(2.0, 0, 2);
+        assert!((n.pow(10.0).log10() - 1024f64.log10()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pow_zero_exponent_and_zero_base() {
+        assert_eq!(BigNumber::new(5.0, 10, 2).pow(0.0).to_string(), "1");
+        assert_eq!(BigNumber::zero().pow(2.0).to_string(), "0");
+        assert_eq!(BigNumber::zero().pow(-1.0).to_string(), "Infinity");
+        assert_eq!(BigNumber::zero().pow(0.0).to_string(), "1");
+    }
+
+    #[test]
+    fn test_pow_negative_base_integer_exponent() {
+        let n = BigNumber::new(-2.0, 0, 2);
+        assert_eq!(n.pow(3.0).to_string(), "-8");
+        assert_eq!(n.pow(2.0).to_string(), "4");
+        assert!(n.pow(0.5).is_nan());
+    }
+
+    #[test]
+    fn test_pow_infinite_base() {
+        assert_eq!(BigNumber::infinity().pow(2.0).to_string(), "Infinity");
+        assert_eq!(BigNumber::infinity().pow(-1.0).to_string(), "0");
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let n = BigNumber::new(4.0, 0, 2);
+        assert_eq!(n.sqrt().to_string(), "2");
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    fn roundtrip(n: BigNumber) -> BigNumber {
+        let json = serde_json::to_string(&n).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip_zero() {
+        let n = BigNumber::zero();
+        assert_eq!(roundtrip(n).to_string(), n.to_string());
+    }
+
+    #[test]
+    fn test_roundtrip_suffixed() {
+        let n = BigNumber::new(1.23, 9, 2);
+        assert_eq!(n.to_string(), "1.23B");
+        assert_eq!(roundtrip(n).to_string(), "1.23B");
+    }
+
+    #[test]
+    fn test_roundtrip_extreme_exponent() {
+        let n = BigNumber::new(5.0, i32::MAX, 2);
+        let back = roundtrip(n);
+        assert_eq!(back.exponent, n.exponent);
+        assert!((back.mantissa - n.mantissa).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_deserialize_struct_form() {
+        let n: BigNumber = serde_json::from_str(r#"{"mantissa":1.5,"exponent":10,"decimals":3}"#).unwrap();
+        assert_eq!(n, BigNumber::new(1.5, 10, 3));
+    }
+
+    #[test]
+    fn test_serialize_is_canonical_string() {
+        let n = BigNumber::new(3.0, 10, 2);
+        assert_eq!(serde_json::to_string(&n).unwrap(), format!("\"{}\"", n.to_string()));
+    }
 }